@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Write;
@@ -17,6 +18,49 @@ impl<const N: usize> From<[LinkItem; N]> for Link {
     }
 }
 
+impl Link {
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &LinkItem> {
+        self.0.iter()
+    }
+
+    /// The link-values whose `rel` relation-type set contains `rel`.
+    pub fn links_with_rel<'a>(&'a self, rel: &'a str) -> impl Iterator<Item = &'a LinkItem> {
+        self.iter()
+            .filter(move |item| item.rel_types().any(|item_rel| item_rel == rel))
+    }
+
+    #[inline]
+    pub fn next(&self) -> Option<Uri> {
+        self.links_with_rel("next").next().and_then(LinkItem::uri)
+    }
+
+    #[inline]
+    pub fn prev(&self) -> Option<Uri> {
+        self.links_with_rel("prev").next().and_then(LinkItem::uri)
+    }
+
+    #[inline]
+    pub fn first(&self) -> Option<Uri> {
+        self.links_with_rel("first").next().and_then(LinkItem::uri)
+    }
+
+    #[inline]
+    pub fn last(&self) -> Option<Uri> {
+        self.links_with_rel("last").next().and_then(LinkItem::uri)
+    }
+
+    /// Resolves every link-value's target against `base`, in place. See
+    /// [`LinkItem::resolve`].
+    pub fn resolve_all(&mut self, base: &Uri) {
+        for item in self.0.iter_mut() {
+            if let Some(resolved) = item.resolve(base) {
+                item.target = resolved.to_string();
+            }
+        }
+    }
+}
+
 impl FromIterator<LinkItem> for Link {
     fn from_iter<T: IntoIterator<Item = LinkItem>>(iter: T) -> Self {
         Link(iter.into_iter().collect())
@@ -27,10 +71,7 @@ impl FromStr for Link {
     type Err = InvalidLink;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.split(',')
-            .map(LinkItem::from_str)
-            .collect::<Result<Box<[LinkItem]>, Self::Err>>()
-            .map(Link)
+        parse_items(s).map(Box::from).map(Link)
     }
 }
 
@@ -59,13 +100,20 @@ impl Header for Link {
         Self: Sized,
         I: Iterator<Item = &'i HeaderValue>,
     {
-        values
-            .next()
-            .ok_or_else(headers::Error::invalid)?
-            .to_str()
-            .map_err(|_| InvalidLink)
-            .and_then(Self::from_str)
-            .map_err(Into::into)
+        let mut items = Vec::new();
+        let mut any = false;
+
+        for value in values {
+            any = true;
+            let value = value.to_str().map_err(|_| InvalidLink)?;
+            items.extend(Link::from_str(value)?.0);
+        }
+
+        if !any {
+            return Err(headers::Error::invalid());
+        }
+
+        Ok(Link(items.into()))
     }
 
     fn encode<E>(&self, values: &mut E)
@@ -81,14 +129,14 @@ impl Header for Link {
 
 #[derive(Debug)]
 pub struct LinkItem {
-    uri: Uri,
-    params: HashMap<String, String>,
+    target: String,
+    params: HashMap<String, ParamValue>,
 }
 
 impl LinkItem {
     pub fn new(uri: Uri) -> LinkItem {
         LinkItem {
-            uri,
+            target: uri.to_string(),
             params: HashMap::default(),
         }
     }
@@ -100,22 +148,121 @@ impl LinkItem {
         V: Into<String>,
     {
         LinkItem {
-            uri,
+            target: uri.to_string(),
             params: params
                 .into_iter()
-                .map(|(key, val)| (key.into(), val.into()))
+                .map(|(key, val)| (key.into(), ParamValue::Plain(val.into())))
                 .collect(),
         }
     }
 
+    /// This link-value's target, parsed as an [`http::Uri`].
+    ///
+    /// An absolute-path target such as `/page/2` parses to a path-only
+    /// `Uri` with no scheme or authority. Other relative references
+    /// (`sub/page`, `../up`, `?query`, ...) have no [`http::Uri`] grammar;
+    /// this returns `None` for those. Use
+    /// [`resolve`](Self::resolve) to turn any target, relative or not,
+    /// into an absolute `Uri` against a known base.
     #[inline]
-    pub fn uri(&self) -> &Uri {
-        &self.uri
+    pub fn uri(&self) -> Option<Uri> {
+        self.target.parse().ok()
     }
 
     #[inline]
     pub fn param(&self, name: &str) -> Option<&str> {
-        self.params.get(name).map(|param| param.as_str())
+        self.params.get(name).map(ParamValue::value)
+    }
+
+    /// Looks up `name`, preferring its RFC 8187 extended form `name*` (an
+    /// internationalized value, already percent- and charset-decoded)
+    /// over the plain `name` when both are present.
+    fn param_preferring_extended(&self, name: &str) -> Option<&str> {
+        self.params
+            .get(&format!("{name}*"))
+            .or_else(|| self.params.get(name))
+            .map(ParamValue::value)
+    }
+
+    /// Looks up `name`, preferring its RFC 8187 extended form `name*` (an
+    /// internationalized value, already percent- and charset-decoded)
+    /// over the plain `name` when both are present.
+    pub fn param_decoded(&self, name: &str) -> Option<Cow<'_, str>> {
+        self.param_preferring_extended(name).map(Cow::Borrowed)
+    }
+
+    /// The RFC 8187 language tag carried by `name`'s extended form (`name*`),
+    /// if it has one and was sent with one — `title*=UTF-8'en'...` carries
+    /// `"en"`, `title*=UTF-8''...` carries none.
+    pub fn param_language(&self, name: &str) -> Option<&str> {
+        match self.params.get(&format!("{name}*"))? {
+            ParamValue::Extended { language, .. } => language.as_deref(),
+            ParamValue::Plain(_) => None,
+        }
+    }
+
+    #[inline]
+    pub fn rel(&self) -> Option<&str> {
+        self.param_preferring_extended("rel")
+    }
+
+    #[inline]
+    pub fn title(&self) -> Option<&str> {
+        self.param_preferring_extended("title")
+    }
+
+    #[inline]
+    pub fn media_type(&self) -> Option<&str> {
+        self.param_preferring_extended("type")
+    }
+
+    #[inline]
+    pub fn hreflang(&self) -> Option<&str> {
+        self.param_preferring_extended("hreflang")
+    }
+
+    #[inline]
+    pub fn anchor(&self) -> Option<&str> {
+        self.param_preferring_extended("anchor")
+    }
+
+    #[inline]
+    pub fn media(&self) -> Option<&str> {
+        self.param_preferring_extended("media")
+    }
+
+    /// The space-separated relation types carried by the `rel` parameter,
+    /// per RFC 8288's `relation-types` production.
+    #[inline]
+    pub fn rel_types(&self) -> impl Iterator<Item = &str> {
+        self.rel()
+            .into_iter()
+            .flat_map(|rel| rel.split(' '))
+            .filter(|rel| !rel.is_empty())
+    }
+
+    /// Resolves this link-value's target against `base`, per RFC 3986 §5
+    /// reference resolution: a target carrying its own scheme is returned
+    /// unchanged, while a relative target is merged with `base`'s
+    /// authority, path and query.
+    ///
+    /// Returns `None` if the target is already absolute (carries its own
+    /// scheme) but still can't be represented as an [`http::Uri`] — e.g.
+    /// `urn:isbn:0451450523` or a `mailto:` target, neither of which has
+    /// the `//`-style authority `http::Uri` requires of an absolute URI.
+    /// This is never conflated with `base` itself: a target that genuinely
+    /// resolves to `base` returns `Some(base.clone())`, not `None`.
+    pub fn resolve(&self, base: &Uri) -> Option<Uri> {
+        resolve_reference(base, &self.target)
+    }
+
+    /// Resolves this link-value's `anchor` parameter against `base`, the
+    /// same way [`resolve`](Self::resolve) resolves the target — per RFC
+    /// 8288, `anchor` is itself frequently a relative reference. Returns
+    /// `None` if there's no `anchor` parameter, or if it can't be resolved
+    /// for the reasons documented on `resolve`.
+    pub fn resolve_anchor(&self, base: &Uri) -> Option<Uri> {
+        resolve_reference(base, self.anchor()?)
     }
 }
 
@@ -123,41 +270,547 @@ impl FromStr for LinkItem {
     type Err = InvalidLink;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (link, parameters) = s
-            .split_once(';')
-            .map(|(a, b)| (a, Some(b)))
-            .unwrap_or((s, None));
-        let link = link.strip_prefix('<').ok_or(InvalidLink)?;
-        let link = link.strip_suffix('>').ok_or(InvalidLink)?;
-        let uri: Uri = link.parse().map_err(|_| InvalidLink)?;
-        let mut params = HashMap::new();
-
-        if let Some(parameters) = parameters {
-            for param in parameters.split(';') {
-                let (name, data) = param.trim().split_once('=').ok_or(InvalidLink)?;
-                params.insert(name.to_string(), data.to_string());
-            }
-        };
+        let mut items = parse_items(s)?.into_iter();
+        let item = items.next().ok_or(InvalidLink)?;
 
-        Ok(LinkItem { uri, params })
+        if items.next().is_some() {
+            return Err(InvalidLink);
+        }
+
+        Ok(item)
     }
 }
 
 impl Display for LinkItem {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         f.write_char('<')?;
-        self.uri.fmt(f)?;
+        f.write_str(&self.target)?;
         f.write_char('>')?;
         for (name, data) in self.params.iter() {
             f.write_str("; ")?;
-            f.write_str(name.as_str())?;
-            f.write_char('=')?;
-            f.write_str(data.as_str())?;
+            match name.strip_suffix('*') {
+                Some(base_name) => {
+                    write_extended_param(f, base_name, data.language(), data.value())?
+                }
+                None if needs_extended_encoding(data.value()) => {
+                    write_extended_param(f, name, data.language(), data.value())?
+                }
+                None => {
+                    f.write_str(name.as_str())?;
+                    f.write_char('=')?;
+                    write_param_value(f, data.value())?;
+                }
+            }
         }
         Ok(())
     }
 }
 
+/// A parameter's decoded value, recording the RFC 8187 language tag
+/// alongside it (if it arrived as an extended `name*` value with one) so
+/// that re-encoding it doesn't silently drop it.
+#[derive(Debug)]
+enum ParamValue {
+    /// A plain, unextended parameter value.
+    Plain(String),
+    /// A decoded RFC 8187 extended value, with its language tag if it had
+    /// one.
+    Extended { language: Option<String>, value: String },
+}
+
+impl ParamValue {
+    fn value(&self) -> &str {
+        match self {
+            ParamValue::Plain(value) | ParamValue::Extended { value, .. } => value,
+        }
+    }
+
+    fn language(&self) -> Option<&str> {
+        match self {
+            ParamValue::Plain(_) => None,
+            ParamValue::Extended { language, .. } => language.as_deref(),
+        }
+    }
+}
+
+/// Writes `name*=UTF-8'<language>'<percent-encoded value>`, the RFC 8187
+/// extended parameter form, for a value that can't be carried as a plain
+/// token or quoted-string.
+fn write_extended_param(
+    f: &mut std::fmt::Formatter,
+    name: &str,
+    language: Option<&str>,
+    value: &str,
+) -> std::fmt::Result {
+    f.write_str(name)?;
+    f.write_str("*=UTF-8'")?;
+    if let Some(language) = language {
+        f.write_str(language)?;
+    }
+    f.write_char('\'')?;
+    f.write_str(&percent_encode(value))
+}
+
+/// Writes `value` as an RFC 8288 parameter value, as a bare token or,
+/// whenever it is empty or holds a separator that would otherwise
+/// terminate the token early, as a quoted-string (escaping any `"` or `\`
+/// it contains).
+fn write_param_value(f: &mut std::fmt::Formatter, value: &str) -> std::fmt::Result {
+    if !needs_quoting(value) {
+        return f.write_str(value);
+    }
+
+    f.write_char('"')?;
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            f.write_char('\\')?;
+        }
+        f.write_char(c)?;
+    }
+    f.write_char('"')
+}
+
+fn needs_quoting(value: &str) -> bool {
+    value.is_empty()
+        || value
+            .chars()
+            .any(|c| matches!(c, ',' | ';' | '"' | '\\' | ' ' | '\t'))
+}
+
+/// Resolves `reference` — the raw text of a link-value's target, which is
+/// frequently a relative reference that [`http::Uri`] has no grammar for —
+/// against `base`, following the RFC 3986 §5.3 component-merge algorithm.
+///
+/// A reference with its own scheme is already absolute and is parsed as
+/// given, returning `None` if `http::Uri` can't represent it (e.g. a
+/// `urn:`/`mailto:`-style scheme with no `//` authority); otherwise its
+/// authority, path and query are merged onto `base`'s. Any fragment is
+/// dropped, since `http::Uri` has no fragment component to carry it in.
+fn resolve_reference(base: &Uri, reference: &str) -> Option<Uri> {
+    let (scheme, authority, path, query) = split_reference(reference);
+
+    if scheme.is_some() {
+        return reference.parse().ok();
+    }
+
+    let (authority, path, query) = if let Some(authority) = authority {
+        (Some(authority.to_string()), path.to_string(), query)
+    } else if path.is_empty() {
+        (
+            base.authority().map(ToString::to_string),
+            base.path().to_string(),
+            query.or_else(|| base.query()),
+        )
+    } else if path.starts_with('/') {
+        (
+            base.authority().map(ToString::to_string),
+            remove_dot_segments(path),
+            query,
+        )
+    } else {
+        (
+            base.authority().map(ToString::to_string),
+            remove_dot_segments(&merge_paths(base, path)),
+            query,
+        )
+    };
+
+    let mut path_and_query = path;
+    if let Some(query) = query {
+        path_and_query.push('?');
+        path_and_query.push_str(query);
+    }
+    if path_and_query.is_empty() {
+        path_and_query.push('/');
+    }
+
+    let mut builder = Uri::builder();
+    if let Some(scheme) = base.scheme() {
+        builder = builder.scheme(scheme.clone());
+    }
+    if let Some(authority) = authority {
+        builder = builder.authority(authority);
+    }
+
+    builder.path_and_query(path_and_query).build().ok()
+}
+
+/// Splits a URI-reference into its `(scheme, authority, path, query)`
+/// components per the RFC 3986 Appendix B grammar, dropping any fragment.
+/// Unlike [`http::Uri::from_str`], this accepts the full relative-reference
+/// grammar (`g`, `../g`, `?y`, ...), not just absolute URIs and
+/// absolute-path request-targets.
+fn split_reference(s: &str) -> (Option<&str>, Option<&str>, &str, Option<&str>) {
+    let s = s.split('#').next().unwrap_or(s);
+
+    let (scheme, rest) = match s.find(':') {
+        Some(i) if is_scheme(&s[..i]) => (Some(&s[..i]), &s[i + 1..]),
+        _ => (None, s),
+    };
+
+    let (authority, rest) = match rest.strip_prefix("//") {
+        Some(rest) => {
+            let end = rest.find(['/', '?']).unwrap_or(rest.len());
+            (Some(&rest[..end]), &rest[end..])
+        }
+        None => (None, rest),
+    };
+
+    match rest.split_once('?') {
+        Some((path, query)) => (scheme, authority, path, Some(query)),
+        None => (scheme, authority, rest, None),
+    }
+}
+
+/// Whether `s` is a valid RFC 3986 `scheme` (`ALPHA *( ALPHA / DIGIT / "+" /
+/// "-" / "." )`).
+fn is_scheme(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Merges a relative-path reference onto `base`'s path, per RFC 3986
+/// §5.3: replaces everything after the last `/` in `base`'s path (its
+/// "directory") with `reference_path`.
+fn merge_paths(base: &Uri, reference_path: &str) -> String {
+    if base.authority().is_some() && base.path().is_empty() {
+        return format!("/{reference_path}");
+    }
+
+    match base.path().rfind('/') {
+        Some(i) => format!("{}{}", &base.path()[..=i], reference_path),
+        None => reference_path.to_string(),
+    }
+}
+
+/// Removes `.` and `..` path segments per the RFC 3986 §5.2.4 algorithm.
+fn remove_dot_segments(path: &str) -> String {
+    let mut input = path.to_string();
+    let mut output = String::new();
+
+    while !input.is_empty() {
+        if input.starts_with("../") {
+            input.replace_range(..3, "");
+        } else if input.starts_with("./") {
+            input.replace_range(..2, "");
+        } else if input.starts_with("/./") {
+            input.replace_range(..3, "/");
+        } else if input == "/." {
+            input.replace_range(..2, "/");
+        } else if input.starts_with("/../") {
+            input.replace_range(..4, "/");
+            remove_last_segment(&mut output);
+        } else if input == "/.." {
+            input.replace_range(..3, "/");
+            remove_last_segment(&mut output);
+        } else if input == "." || input == ".." {
+            input.clear();
+        } else {
+            let end = if let Some(rest) = input.strip_prefix('/') {
+                rest.find('/').map_or(input.len(), |i| i + 1)
+            } else {
+                input.find('/').unwrap_or(input.len())
+            };
+            output.push_str(&input[..end]);
+            input.replace_range(..end, "");
+        }
+    }
+
+    output
+}
+
+fn remove_last_segment(output: &mut String) {
+    match output.rfind('/') {
+        Some(i) => output.truncate(i),
+        None => output.clear(),
+    }
+}
+
+/// Tokenizer states used to walk a `Link` header field value byte-by-byte.
+///
+/// `,` and `;` act as separators in [`State::BetweenParams`] and
+/// [`State::Token`], and are rejected in [`State::ParamName`]; inside a
+/// `<...>` target or a `"..."` quoted value they're just ordinary
+/// characters.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum State {
+    /// Before the `<` that starts a link-value's target URI.
+    BeforeUri,
+    /// Inside the `<...>` delimited target URI.
+    InAngleUri,
+    /// After a target URI or a parameter value, waiting for `;` (another
+    /// parameter), `,` (the next link-value) or end of input.
+    BetweenParams,
+    /// Reading a parameter name, up to the `=`.
+    ParamName,
+    /// Just past the `=`, deciding between a quoted or unquoted value.
+    BeforeValue,
+    /// Inside a `"..."` quoted parameter value; `\"` is an escaped quote.
+    QuotedValue,
+    /// Reading an unquoted parameter value token.
+    Token,
+}
+
+/// Parses a full `Link` header field value into its link-values, using a
+/// small state machine so that `,` and `;` inside a `<...>` target or a
+/// `"..."` quoted parameter value don't get mistaken for separators.
+fn parse_items(s: &str) -> Result<Vec<LinkItem>, InvalidLink> {
+    let mut items = Vec::new();
+    let mut state = State::BeforeUri;
+
+    let mut uri: Option<&str> = None;
+    let mut params: HashMap<String, ParamValue> = HashMap::new();
+
+    let mut start = 0;
+    let mut name = String::new();
+    let mut value = String::new();
+
+    macro_rules! finish_item {
+        () => {{
+            items.push(LinkItem {
+                target: uri.take().ok_or(InvalidLink)?.to_string(),
+                params: std::mem::take(&mut params),
+            });
+        }};
+    }
+
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match state {
+            State::BeforeUri => {
+                if c.is_whitespace() {
+                    continue;
+                }
+                if c != '<' {
+                    return Err(InvalidLink);
+                }
+                start = i + 1;
+                state = State::InAngleUri;
+            }
+            State::InAngleUri => {
+                if c == '>' {
+                    uri = Some(&s[start..i]);
+                    state = State::BetweenParams;
+                }
+            }
+            State::BetweenParams => {
+                if c.is_whitespace() {
+                    continue;
+                }
+                match c {
+                    ';' => state = State::ParamName,
+                    ',' => {
+                        finish_item!();
+                        state = State::BeforeUri;
+                    }
+                    _ => return Err(InvalidLink),
+                }
+                name.clear();
+            }
+            State::ParamName => {
+                if c == '=' {
+                    state = State::BeforeValue;
+                } else if c == ';' || c == ',' {
+                    // A `;` or `,` before any `=` means an empty or
+                    // name-only `link-param` segment (`;;`, `; ;`,
+                    // `;foo,`), which isn't valid — reject it the same way
+                    // a trailing `;` at the end of input already is,
+                    // rather than letting the separator leak into `name`.
+                    return Err(InvalidLink);
+                } else if c.is_whitespace() {
+                    if !name.is_empty() {
+                        state = State::BeforeValue;
+                        // Consume up to the `=` that must follow.
+                        loop {
+                            match chars.peek() {
+                                Some((_, c)) if c.is_whitespace() => {
+                                    chars.next();
+                                }
+                                Some((_, '=')) => {
+                                    chars.next();
+                                    break;
+                                }
+                                _ => return Err(InvalidLink),
+                            }
+                        }
+                    }
+                } else {
+                    name.push(c);
+                }
+            }
+            State::BeforeValue => {
+                value.clear();
+                if c == '"' {
+                    state = State::QuotedValue;
+                } else if c.is_whitespace() {
+                    continue;
+                } else {
+                    value.push(c);
+                    state = State::Token;
+                }
+            }
+            State::QuotedValue => {
+                if c == '\\' {
+                    if let Some(&(_, next)) = chars.peek() {
+                        value.push(next);
+                        chars.next();
+                    } else {
+                        return Err(InvalidLink);
+                    }
+                } else if c == '"' {
+                    insert_param(
+                        &mut params,
+                        std::mem::take(&mut name),
+                        std::mem::take(&mut value),
+                    )?;
+                    state = State::BetweenParams;
+                } else {
+                    value.push(c);
+                }
+            }
+            State::Token => match c {
+                ';' => {
+                    insert_param(
+                        &mut params,
+                        std::mem::take(&mut name),
+                        std::mem::take(&mut value),
+                    )?;
+                    state = State::ParamName;
+                    name.clear();
+                }
+                ',' => {
+                    insert_param(
+                        &mut params,
+                        std::mem::take(&mut name),
+                        std::mem::take(&mut value),
+                    )?;
+                    finish_item!();
+                    state = State::BeforeUri;
+                }
+                c if c.is_whitespace() => {
+                    insert_param(
+                        &mut params,
+                        std::mem::take(&mut name),
+                        std::mem::take(&mut value),
+                    )?;
+                    state = State::BetweenParams;
+                }
+                c => value.push(c),
+            },
+        }
+    }
+
+    match state {
+        State::BetweenParams => {
+            finish_item!();
+        }
+        State::Token => {
+            insert_param(
+                &mut params,
+                std::mem::take(&mut name),
+                std::mem::take(&mut value),
+            )?;
+            finish_item!();
+        }
+        _ => return Err(InvalidLink),
+    }
+
+    Ok(items)
+}
+
+/// Inserts a parsed `name=value` pair into `params`, decoding it first if
+/// `name` is an RFC 8187 extended parameter (ends in `*`).
+fn insert_param(
+    params: &mut HashMap<String, ParamValue>,
+    name: String,
+    value: String,
+) -> Result<(), InvalidLink> {
+    let value = if name.ends_with('*') {
+        let (language, value) = decode_extended_value(&value)?;
+        ParamValue::Extended { language, value }
+    } else {
+        ParamValue::Plain(value)
+    };
+    params.insert(name, value);
+    Ok(())
+}
+
+/// Decodes an RFC 8187 `ext-value` (`charset "'" [ language ] "'" value-chars`),
+/// percent-decoding `value-chars` and interpreting the result as `charset`.
+/// Only the `UTF-8` and `US-ASCII` charsets are supported. Returns the
+/// language tag alongside the decoded value, if one was present, so that
+/// re-encoding the value can carry it forward.
+fn decode_extended_value(raw: &str) -> Result<(Option<String>, String), InvalidLink> {
+    let (charset, rest) = raw.split_once('\'').ok_or(InvalidLink)?;
+    let (language, pct_encoded) = rest.split_once('\'').ok_or(InvalidLink)?;
+
+    if !charset.eq_ignore_ascii_case("UTF-8") && !charset.eq_ignore_ascii_case("US-ASCII") {
+        return Err(InvalidLink);
+    }
+
+    let bytes = percent_decode(pct_encoded)?;
+    let value = String::from_utf8(bytes).map_err(|_| InvalidLink)?;
+    let language = if language.is_empty() {
+        None
+    } else {
+        Some(language.to_string())
+    };
+
+    Ok((language, value))
+}
+
+fn percent_decode(s: &str) -> Result<Vec<u8>, InvalidLink> {
+    let mut bytes = s.bytes();
+    let mut decoded = Vec::with_capacity(s.len());
+
+    while let Some(byte) = bytes.next() {
+        if byte != b'%' {
+            decoded.push(byte);
+            continue;
+        }
+
+        let hi = bytes.next().ok_or(InvalidLink)?;
+        let lo = bytes.next().ok_or(InvalidLink)?;
+        let hex = [hi, lo];
+        let hex = std::str::from_utf8(&hex).map_err(|_| InvalidLink)?;
+        decoded.push(u8::from_str_radix(hex, 16).map_err(|_| InvalidLink)?);
+    }
+
+    Ok(decoded)
+}
+
+/// Percent-encodes `value` as RFC 8187 `value-chars`, leaving `attr-char`
+/// bytes (alphanumerics and a handful of symbols) untouched.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        if is_attr_char(byte) {
+            encoded.push(byte as char);
+        } else {
+            write!(encoded, "%{byte:02X}").unwrap();
+        }
+    }
+
+    encoded
+}
+
+fn is_attr_char(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric()
+        || matches!(
+            byte,
+            b'!' | b'#' | b'$' | b'&' | b'+' | b'-' | b'.' | b'^' | b'_' | b'`' | b'|' | b'~'
+        )
+}
+
+/// Whether `value` can appear as-is (quoted or as a token) in an RFC 8288
+/// parameter, versus needing the RFC 8187 `name*=charset'lang'...` form.
+fn needs_extended_encoding(value: &str) -> bool {
+    !value.is_ascii() || value.chars().any(|c| c.is_control())
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct InvalidLink;
 
@@ -175,3 +828,230 @@ impl From<InvalidLink> for headers::Error {
         headers::Error::invalid()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The RFC 3986 §5.4.1 "normal examples" reference-resolution table,
+    /// resolved against the RFC's own base URI `http://a/b/c/d;p?q`.
+    ///
+    /// `g:h` is omitted: `http::Uri` parses a bare `scheme:opaque` reference
+    /// like this as CONNECT-style authority-form rather than as an absolute
+    /// URI, so `reference.parse()` can't round-trip it. Fragment-carrying
+    /// references resolve to the same target as their fragment-free
+    /// counterparts, since `http::Uri` has no fragment component to keep.
+    #[test]
+    fn rfc3986_reference_resolution_examples() {
+        let base: Uri = "http://a/b/c/d;p?q".parse().unwrap();
+
+        let examples = [
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g/"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q"),
+            ("g#s", "http://a/b/c/g"),
+            ("g?y#s", "http://a/b/c/g?y"),
+            (";x", "http://a/b/c/;x"),
+            ("g;x", "http://a/b/c/g;x"),
+            ("g;x?y#s", "http://a/b/c/g;x?y"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../", "http://a/"),
+            ("../../g", "http://a/g"),
+        ];
+
+        for (reference, expected) in examples {
+            let item = LinkItem::from_str(&format!("<{reference}>")).unwrap();
+            assert_eq!(
+                item.resolve(&base).unwrap().to_string(),
+                expected,
+                "resolving {reference:?} against {base}"
+            );
+        }
+    }
+
+    #[test]
+    fn quoted_value_protects_separators_from_tokenizer() {
+        // `,` and `;` inside a quoted parameter value must not be mistaken
+        // for the link-value or parameter separators they normally are.
+        let link: Link = r#"<http://example.com/a>; title="a, b; c", <http://example.com/b>; rel=next"#
+            .parse()
+            .unwrap();
+        let mut items = link.iter();
+
+        let a = items.next().unwrap();
+        assert_eq!(a.title(), Some("a, b; c"));
+
+        let b = items.next().unwrap();
+        assert_eq!(b.rel(), Some("next"));
+
+        assert!(items.next().is_none());
+    }
+
+    #[test]
+    fn consecutive_or_stray_semicolons_are_rejected() {
+        // A stray/doubled `;` is an empty `link-param` segment, which isn't
+        // valid — it must not leak into the next parameter's name (which
+        // would silently drop `rel=next` under the key `";rel"`), and it
+        // must be rejected the same way regardless of whitespace around it.
+        assert!(LinkItem::from_str("<http://example.com/a>;;rel=next").is_err());
+        assert!(LinkItem::from_str("<http://example.com/a>;; rel=next").is_err());
+        assert!(LinkItem::from_str("<http://example.com/a>; ;rel=next").is_err());
+    }
+
+    #[test]
+    fn rel_types_splits_on_space_and_filters_links_with_rel() {
+        let link: Link =
+            r#"<http://example.com/a>; rel="next alternate", <http://example.com/b>; rel=next"#
+                .parse()
+                .unwrap();
+
+        let a = link.iter().next().unwrap();
+        assert_eq!(a.rel_types().collect::<Vec<_>>(), vec!["next", "alternate"]);
+
+        let next_targets: Vec<_> = link
+            .links_with_rel("next")
+            .map(|item| item.uri().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            next_targets,
+            vec!["http://example.com/a", "http://example.com/b"]
+        );
+
+        assert_eq!(link.links_with_rel("alternate").count(), 1);
+        assert_eq!(link.links_with_rel("missing").count(), 0);
+    }
+
+    #[test]
+    fn pagination_helpers_find_their_relation_type() {
+        let link: Link = concat!(
+            "<http://example.com/p2>; rel=next, ",
+            "<http://example.com/p0>; rel=prev, ",
+            "<http://example.com/p1>; rel=first, ",
+            "<http://example.com/p9>; rel=last"
+        )
+        .parse()
+        .unwrap();
+
+        assert_eq!(link.next().unwrap(), "http://example.com/p2");
+        assert_eq!(link.prev().unwrap(), "http://example.com/p0");
+        assert_eq!(link.first().unwrap(), "http://example.com/p1");
+        assert_eq!(link.last().unwrap(), "http://example.com/p9");
+    }
+
+    #[test]
+    fn pagination_helper_is_none_when_relation_absent() {
+        let link: Link = "<http://example.com/p2>; rel=next".parse().unwrap();
+        assert!(link.prev().is_none());
+    }
+
+    #[test]
+    fn extended_param_round_trips_value_and_language_tag() {
+        let item: LinkItem = "<http://example.com/a>; title*=UTF-8'en'caf%C3%A9"
+            .parse()
+            .unwrap();
+
+        assert_eq!(item.param_decoded("title").as_deref(), Some("café"));
+        assert_eq!(item.param_language("title"), Some("en"));
+        assert_eq!(
+            item.to_string(),
+            "<http://example.com/a>; title*=UTF-8'en'caf%C3%A9"
+        );
+    }
+
+    #[test]
+    fn typed_accessors_prefer_the_extended_form() {
+        // title() (and the other typed accessors) must consult `name*` the
+        // same way param_decoded() does — otherwise an internationalized
+        // value is invisible to every accessor except param_decoded().
+        let item: LinkItem = "<http://example.com/a>; title*=UTF-8'en'caf%C3%A9"
+            .parse()
+            .unwrap();
+        assert_eq!(item.title(), Some("café"));
+    }
+
+    #[test]
+    fn extended_param_without_language_tag_round_trips() {
+        let item: LinkItem = "<http://example.com/a>; title*=UTF-8''caf%C3%A9"
+            .parse()
+            .unwrap();
+
+        assert_eq!(item.param_language("title"), None);
+        assert_eq!(
+            item.to_string(),
+            "<http://example.com/a>; title*=UTF-8''caf%C3%A9"
+        );
+    }
+
+    #[test]
+    fn decode_merges_multiple_link_header_field_lines() {
+        let values = [
+            HeaderValue::from_static("<http://example.com/a>; rel=next"),
+            HeaderValue::from_static("<http://example.com/b>; rel=prev"),
+        ];
+
+        let link = Link::decode(&mut values.iter()).unwrap();
+        let rels: Vec<_> = link.iter().map(|item| item.rel().unwrap()).collect();
+
+        assert_eq!(rels, vec!["next", "prev"]);
+    }
+
+    #[test]
+    fn decode_rejects_no_field_lines() {
+        let values: [HeaderValue; 0] = [];
+        assert!(Link::decode(&mut values.iter()).is_err());
+    }
+
+    #[test]
+    fn ordinary_relative_targets_parse() {
+        // Before this fix, a target with no scheme and no leading `/` failed
+        // to parse at all, because the tokenizer validated it as an
+        // `http::Uri` (which has no grammar for ordinary relative-path
+        // references) instead of storing the raw text.
+        let item = LinkItem::from_str("<sub/page>; rel=next").unwrap();
+        assert_eq!(item.uri(), None);
+        assert_eq!(item.rel(), Some("next"));
+    }
+
+    #[test]
+    fn resolve_is_none_for_an_absolute_target_uri_cant_represent() {
+        // A urn:-style target carries its own scheme but has no `//`
+        // authority, which `http::Uri` has no grammar for. resolve() must
+        // surface that as None rather than silently falling back to
+        // `base` — a fallback indistinguishable from a target that
+        // genuinely resolves to `base`.
+        let base: Uri = "http://a/b/c/d;p?q".parse().unwrap();
+
+        let item = LinkItem::from_str("<urn:isbn:0451450523>; rel=describedby").unwrap();
+        assert_eq!(item.resolve(&base), None);
+
+        // A target that genuinely resolves to `base` is still `Some`, never
+        // conflated with the failure case above.
+        let item = LinkItem::from_str("<>; rel=self").unwrap();
+        assert_eq!(item.resolve(&base), Some(base));
+    }
+
+    #[test]
+    fn resolve_anchor_resolves_the_anchor_parameter_like_the_target() {
+        let base: Uri = "http://a/b/c/d;p?q".parse().unwrap();
+
+        let item = LinkItem::from_str(r#"<g>; rel=next; anchor="../up""#).unwrap();
+        assert_eq!(
+            item.resolve_anchor(&base).unwrap().to_string(),
+            "http://a/b/up"
+        );
+
+        let item = LinkItem::from_str("<g>; rel=next").unwrap();
+        assert_eq!(item.resolve_anchor(&base), None);
+    }
+}